@@ -0,0 +1,76 @@
+use crate::util::access_sys_fn;
+use crate::Domain;
+use std::ffi::CString;
+
+/// A named, numeric counter recorded against a [`Domain`], so VTune can chart a value (memory in
+/// use, queue depth, work items processed, ...) over time.
+pub struct Counter(*mut ittapi_sys::__itt_counter);
+impl Counter {
+    /// Create a new counter with the given name on `domain`.
+    ///
+    /// ```
+    /// # use ittapi::{Counter, Domain};
+    /// let domain = Domain::new("test-domain");
+    /// let counter = Counter::new(&domain, "queue-depth");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` contains a `0` byte.
+    #[must_use]
+    pub fn new(domain: &Domain, name: &str) -> Self {
+        #[cfg(unix)]
+        let create_fn = access_sys_fn!(__itt_counter_create_ptr__3_0);
+        #[cfg(windows)]
+        let create_fn = access_sys_fn!(__itt_counter_createA_ptr__3_0);
+        let c_string =
+            CString::new(name).expect("unable to create a CString; does it contain a 0 byte?");
+        let counter = unsafe { create_fn(c_string.as_ptr(), domain.as_ptr()) };
+        Self(counter)
+    }
+
+    /// Increment this counter by one.
+    pub fn inc(&self) {
+        let inc_fn = access_sys_fn!(__itt_counter_inc);
+        unsafe { inc_fn(self.0) }
+    }
+
+    /// Increment this counter by `delta`.
+    pub fn inc_by(&self, delta: u64) {
+        let inc_delta_fn = access_sys_fn!(__itt_counter_inc_delta);
+        unsafe { inc_delta_fn(self.0, delta) }
+    }
+
+    /// Set this counter to `value`.
+    pub fn set(&self, value: u64) {
+        let set_value_fn = access_sys_fn!(__itt_counter_set_value);
+        let mut value = value;
+        unsafe { set_value_fn(self.0, std::ptr::addr_of_mut!(value).cast()) }
+    }
+}
+
+/// As discussed in the [ITT documentation], the `__itt_counter` structure is accessible by any
+/// thread in the process.
+///
+/// [ITT documentation]:
+///     https://www.intel.com/content/www/us/en/docs/vtune-profiler/user-guide/current/instrumentation-and-tracing-technology-apis.html
+unsafe impl Sync for Counter {}
+
+impl Drop for Counter {
+    fn drop(&mut self) {
+        let destroy_fn = access_sys_fn!(__itt_counter_destroy);
+        unsafe { destroy_fn(self.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "unable to create a CString; does it contain a 0 byte?")]
+    fn zero_byte() {
+        let domain = Domain::new("test-domain");
+        let _counter = Counter::new(&domain, "zero\0byte\0name");
+    }
+}
@@ -9,7 +9,8 @@ use std::ffi::CString;
 pub struct Domain(*mut ittapi_sys::__itt_domain);
 impl Domain {
     /// Create a new domain. Note that, if the `ittnotify` library is not initialized, this call
-    /// will succeed but the domain will be invalid; see discussion TODO.
+    /// will succeed but the domain will be invalid; see [`is_valid`](Self::is_valid) and
+    /// [`try_new`](Self::try_new) for ways to detect that case.
     ///
     /// ```
     /// # use ittapi::Domain;
@@ -31,6 +32,35 @@ impl Domain {
         Self(domain)
     }
 
+    /// Create a new domain, returning `None` if the `ittnotify` collector isn't attached and the
+    /// domain would be invalid. This lets callers skip building expensive per-task
+    /// instrumentation when running outside VTune.
+    ///
+    /// ```
+    /// # use ittapi::Domain;
+    /// if let Some(domain) = Domain::try_new("test-domain") {
+    ///     let _task = domain.task_begin("parse");
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain name contains a `0` byte.
+    #[must_use]
+    pub fn try_new(name: &str) -> Option<Self> {
+        let domain = Self::new(name);
+        domain.is_valid().then_some(domain)
+    }
+
+    /// Check whether this domain is valid, i.e. whether the `ittnotify` collector was attached
+    /// when the domain was created. An invalid domain's task, frame, and counter calls are
+    /// harmless no-ops, so checking this is optional, but it lets callers skip expensive
+    /// instrumentation setup when no collector is present.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.0.is_null()
+    }
+
     /// Use the `__itt_domain` pointer internally.
     pub(crate) fn as_ptr(&self) -> *const ittapi_sys::__itt_domain {
         self.0.cast_const()
@@ -0,0 +1,65 @@
+use crate::util::access_sys_fn;
+use crate::Domain;
+
+impl Domain {
+    /// Begin a frame on this domain explicitly; pair with a matching [`frame_end`](Self::frame_end)
+    /// call. Unlike a [`Task`](crate::Task), a frame does not need to nest: frames on the same
+    /// domain may overlap across threads, which makes them suited to measuring throughput (e.g.
+    /// frames per second) rather than bracketing a single call stack.
+    ///
+    /// For lexically-scoped frames, prefer [`frame`](Self::frame), which returns an RAII guard
+    /// instead of requiring a matching `frame_end` call.
+    ///
+    /// ```
+    /// # use ittapi::Domain;
+    /// let domain = Domain::new("test-domain");
+    /// domain.frame_begin();
+    /// domain.frame_end();
+    /// ```
+    pub fn frame_begin(&self) {
+        let begin_fn = access_sys_fn!(__itt_frame_begin_v3);
+        unsafe { begin_fn(self.as_ptr(), std::ptr::null_mut()) };
+    }
+
+    /// End the most recently begun, not-yet-ended frame on this domain; see
+    /// [`frame_begin`](Self::frame_begin).
+    pub fn frame_end(&self) {
+        let end_fn = access_sys_fn!(__itt_frame_end_v3);
+        unsafe { end_fn(self.as_ptr(), std::ptr::null_mut()) };
+    }
+
+    /// Begin a frame on this domain, returning a guard that ends the frame when dropped. See
+    /// [`frame_begin`](Self::frame_begin) for the semantics of a frame.
+    ///
+    /// ```
+    /// # use ittapi::Domain;
+    /// let domain = Domain::new("test-domain");
+    /// let _frame = domain.frame();
+    /// ```
+    #[must_use]
+    pub fn frame(&self) -> Frame<'_> {
+        self.frame_begin();
+        Frame {
+            domain: self,
+            _not_send: std::marker::PhantomData,
+        }
+    }
+}
+
+/// An RAII guard representing an open frame on a [`Domain`]; see [`Domain::frame`]. Ends the
+/// frame when dropped.
+///
+/// The implicit frame this guard ends is tracked per-thread, so the guard is deliberately
+/// `!Send`: moving it to another thread and dropping it there would end that thread's pending
+/// frame instead of this one's.
+#[must_use]
+pub struct Frame<'a> {
+    domain: &'a Domain,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        self.domain.frame_end();
+    }
+}
@@ -0,0 +1,21 @@
+//! Safe Rust bindings for Intel's Instrumentation and Tracing Technology (ITT) API, which VTune
+//! and other Intel performance tools use to collect and correlate profiling data. See the
+//! [ITT API documentation] for background on the underlying C API.
+//!
+//! [ITT API documentation]:
+//!     https://www.intel.com/content/www/us/en/docs/vtune-profiler/user-guide/current/instrumentation-and-tracing-technology-apis.html
+
+mod counter;
+mod domain;
+mod frame;
+mod pt_region;
+mod string_handle;
+mod task;
+mod util;
+
+pub use counter::Counter;
+pub use domain::Domain;
+pub use frame::Frame;
+pub use pt_region::PtRegion;
+pub use string_handle::StringHandle;
+pub use task::Task;
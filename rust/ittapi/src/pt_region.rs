@@ -0,0 +1,79 @@
+use crate::util::access_sys_fn;
+use std::ffi::CString;
+
+/// A named Processor Trace (PT) region, used to mark the start and end of a span of code that
+/// should be analyzed with hardware Processor Trace. See the [PT API] documentation for more
+/// information.
+///
+/// [PT API]:
+///     https://www.intel.com/content/www/us/en/docs/vtune-profiler/user-guide/current/instrumentation-and-tracing-technology-apis.html
+pub struct PtRegion(ittapi_sys::__itt_pt_region);
+impl PtRegion {
+    /// Create a new, named PT region.
+    ///
+    /// ```
+    /// # use ittapi::PtRegion;
+    /// let region = PtRegion::new("test-pt-region");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region name contains a `0` byte.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        #[cfg(unix)]
+        let create_fn = access_sys_fn!(__itt_pt_region_create_ptr__3_0);
+        #[cfg(windows)]
+        let create_fn = access_sys_fn!(__itt_pt_region_createA_ptr__3_0);
+        let c_string =
+            CString::new(name).expect("unable to create a CString; does it contain a 0 byte?");
+        let region = unsafe { create_fn(c_string.as_ptr()) };
+        Self(region)
+    }
+
+    /// Mark the beginning of this PT region on the current thread.
+    ///
+    /// Calls to `begin` and [`end`](Self::end) must be balanced and properly nested within a
+    /// thread, the same way matching braces would be; interleaving two regions' `begin`/`end`
+    /// calls on the same thread is not supported. If the `ittnotify` collector is not loaded, this
+    /// call is a no-op.
+    pub fn begin(&self) {
+        let begin_fn = access_sys_fn!(__itt_mark_pt_region_begin);
+        unsafe { begin_fn(self.0) }
+    }
+
+    /// Mark the end of this PT region on the current thread; see [`begin`](Self::begin) for the
+    /// nesting requirements this must satisfy.
+    pub fn end(&self) {
+        let end_fn = access_sys_fn!(__itt_mark_pt_region_end);
+        unsafe { end_fn(self.0) }
+    }
+}
+
+/// As discussed in the [ITT documentation], the `__itt_pt_region` handle is accessible by any
+/// thread in the process.
+///
+/// [ITT documentation]:
+///     https://www.intel.com/content/www/us/en/docs/vtune-profiler/user-guide/current/instrumentation-and-tracing-technology-apis.html
+unsafe impl Sync for PtRegion {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "unable to create a CString; does it contain a 0 byte?")]
+    fn zero_byte() {
+        let _region = PtRegion::new("zero\0byte\0name");
+    }
+
+    /// Without a collector loaded, `begin`/`end` (even nested) must be harmless no-ops.
+    #[test]
+    fn begin_end_is_a_no_op_without_a_collector() {
+        let region = PtRegion::new("test-pt-region");
+        region.begin();
+        region.begin();
+        region.end();
+        region.end();
+    }
+}
@@ -0,0 +1,58 @@
+use crate::util::access_sys_fn;
+use std::ffi::CString;
+
+/// An interned name, passed by reference to [`Domain::task_begin_with`](crate::Domain::task_begin_with).
+/// Frames take no name, and [`Counter::new`](crate::Counter::new) takes a `&str` rather than a
+/// `StringHandle`.
+///
+/// Creating a `StringHandle` registers the name with the `ittnotify` collector once; reusing the
+/// handle avoids the allocation and registration cost of passing a fresh name on every call,
+/// which matters for APIs like [`Domain::task_begin_with`](crate::Domain::task_begin_with) that
+/// may be invoked in a hot loop.
+pub struct StringHandle(*mut ittapi_sys::__itt_string_handle);
+impl StringHandle {
+    /// Create a new, interned string handle.
+    ///
+    /// ```
+    /// # use ittapi::StringHandle;
+    /// let name = StringHandle::new("parse");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` contains a `0` byte.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        #[cfg(unix)]
+        let create_fn = access_sys_fn!(__itt_string_handle_create_ptr__3_0);
+        #[cfg(windows)]
+        let create_fn = access_sys_fn!(__itt_string_handle_createA_ptr__3_0);
+        let c_string =
+            CString::new(name).expect("unable to create a CString; does it contain a 0 byte?");
+        let handle = unsafe { create_fn(c_string.as_ptr()) };
+        Self(handle)
+    }
+
+    /// Use the `__itt_string_handle` pointer internally.
+    pub(crate) fn as_ptr(&self) -> *mut ittapi_sys::__itt_string_handle {
+        self.0
+    }
+}
+
+/// As discussed in the [ITT documentation], the `__itt_string_handle` structure is accessible by
+/// any thread in the process.
+///
+/// [ITT documentation]:
+///     https://www.intel.com/content/www/us/en/docs/vtune-profiler/user-guide/current/instrumentation-and-tracing-technology-apis.html
+unsafe impl Sync for StringHandle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "unable to create a CString; does it contain a 0 byte?")]
+    fn zero_byte() {
+        let _handle = StringHandle::new("zero\0byte\0name");
+    }
+}
@@ -0,0 +1,88 @@
+use crate::util::access_sys_fn;
+use crate::{Domain, StringHandle};
+
+impl Domain {
+    /// Begin a task on this domain, returning a guard that ends the task when dropped.
+    ///
+    /// This creates a fresh [`StringHandle`] for `name` on every call; for tasks begun
+    /// repeatedly (e.g. in a loop), create a `StringHandle` once and pass it to
+    /// [`task_begin_with`](Self::task_begin_with) instead, to avoid re-registering the name
+    /// each time.
+    ///
+    /// Tasks on the same domain from the same thread nest as a stack: a task begun while another
+    /// is still open becomes its child, and must end (i.e., its guard must drop) before the
+    /// parent does. Using the returned guard's lexical scope is the easiest way to keep this
+    /// balanced.
+    ///
+    /// ```
+    /// # use ittapi::Domain;
+    /// let domain = Domain::new("test-domain");
+    /// let _task = domain.task_begin("parse");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` contains a `0` byte.
+    #[must_use]
+    pub fn task_begin(&self, name: &str) -> Task<'_> {
+        self.task_begin_with(&StringHandle::new(name))
+    }
+
+    /// Begin a task on this domain using an already-interned [`StringHandle`], returning a guard
+    /// that ends the task when dropped. See [`task_begin`](Self::task_begin) for the nesting
+    /// rules this must follow.
+    #[must_use]
+    pub fn task_begin_with(&self, name: &StringHandle) -> Task<'_> {
+        let begin_fn = access_sys_fn!(__itt_task_begin);
+        unsafe {
+            begin_fn(
+                self.as_ptr(),
+                ittapi_sys::__itt_null,
+                ittapi_sys::__itt_null,
+                name.as_ptr(),
+            )
+        };
+        Task {
+            domain: self,
+            _not_send: std::marker::PhantomData,
+        }
+    }
+}
+
+/// An RAII guard representing an open task on a [`Domain`]; see [`Domain::task_begin`]. Ends the
+/// task when dropped, or immediately via [`end`](Self::end) for non-lexical scopes.
+///
+/// A task must end on the same thread that began it (the underlying nesting is tracked
+/// per-thread), so this guard is deliberately `!Send`: moving it to another thread and dropping
+/// it there would end that thread's task instead of this one's.
+#[must_use]
+pub struct Task<'a> {
+    domain: &'a Domain,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Task<'_> {
+    /// End this task explicitly, for cases where relying on the guard's drop would not end the
+    /// task at the right point (e.g. it must end before some other code not in its lexical
+    /// scope).
+    pub fn end(self) {}
+}
+
+impl Drop for Task<'_> {
+    fn drop(&mut self) {
+        let end_fn = access_sys_fn!(__itt_task_end);
+        unsafe { end_fn(self.domain.as_ptr()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "unable to create a CString; does it contain a 0 byte?")]
+    fn zero_byte() {
+        let domain = Domain::new("test-domain");
+        let _task = domain.task_begin("zero\0byte\0name");
+    }
+}
@@ -0,0 +1,10 @@
+/// Access a function from the `ittapi-sys` crate by name, so call sites don't need to repeat the
+/// crate path at every use. The macro only names the function; calling it still needs an
+/// `unsafe` block at the call site.
+macro_rules! access_sys_fn {
+    ($name:ident) => {
+        ittapi_sys::$name
+    };
+}
+
+pub(crate) use access_sys_fn;